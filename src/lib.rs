@@ -1,4 +1,5 @@
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
 use std::{
     fs::File,
     io::{Read, Write, Error, ErrorKind},
@@ -6,16 +7,214 @@ use std::{
     path::Path,
 };
 
-#[derive(Debug)]
+/// The serialization format used to read and write a config file.
+///
+/// The format is inferred from the filename extension by default but can be
+/// overridden with [`ConfigFile::format`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Toml,
+    Yaml,
+}
+
+impl Format {
+    /// Guess the format from a filename's extension, falling back to JSON.
+    fn from_filename(filename: &str) -> Self {
+        match Path::new(filename)
+            .extension()
+            .and_then(|ext| ext.to_str())
+        {
+            Some("toml") => Format::Toml,
+            Some("yaml") | Some("yml") => Format::Yaml,
+            _ => Format::Json,
+        }
+    }
+
+    fn serialize<T: Serialize>(&self, config: &T) -> Result<String, Error> {
+        match self {
+            Format::Json => serde_json::to_string_pretty(config)
+                .map_err(|error| Error::new(ErrorKind::InvalidData, error)),
+            Format::Toml => toml::to_string_pretty(config)
+                .map_err(|error| Error::new(ErrorKind::InvalidData, error)),
+            Format::Yaml => serde_yaml::to_string(config)
+                .map_err(|error| Error::new(ErrorKind::InvalidData, error)),
+        }
+    }
+
+    fn deserialize<T: DeserializeOwned>(&self, contents: &str) -> Result<T, Error> {
+        match self {
+            Format::Json => serde_json::from_str(contents)
+                .map_err(|error| Error::new(ErrorKind::InvalidData, error)),
+            Format::Toml => toml::from_str(contents)
+                .map_err(|error| Error::new(ErrorKind::InvalidData, error)),
+            Format::Yaml => serde_yaml::from_str(contents)
+                .map_err(|error| Error::new(ErrorKind::InvalidData, error)),
+        }
+    }
+}
+
+/// A single layer that can contribute values to a merged configuration.
+///
+/// Sources are merged lowest-priority first, so later sources override
+/// individual fields of earlier ones.
+#[derive(Debug, Clone)]
+pub enum Source {
+    /// The builder's `default_config`, serialized into a value.
+    Default,
+    /// A config file at a specific path, parsed using its own extension.
+    File(String),
+    /// Process environment variables under a configurable prefix.
+    Environment(EnvSource),
+}
+
+/// Reads process environment variables under a prefix and maps them onto the
+/// fields of `T`.
+///
+/// With the default `__` separator, `APP_DATABASE__HOST=localhost` becomes the
+/// nested key `database.host`. Scalar values are parsed as JSON when possible
+/// (so `APP_AGE=32` yields a number) and fall back to strings otherwise.
+#[derive(Debug, Clone)]
+pub struct EnvSource {
+    pub prefix: String,
+    pub separator: String,
+}
+
+impl EnvSource {
+    pub fn new(prefix: String) -> Self {
+        Self {
+            prefix,
+            separator: "__".to_string(),
+        }
+    }
+
+    pub fn separator(mut self, separator: String) -> Self {
+        self.separator = separator;
+
+        self
+    }
+
+    /// Collect the matching variables into a nested value tree.
+    fn collect(&self) -> Value {
+        let mut root = Value::Object(serde_json::Map::new());
+
+        for (key, value) in std::env::vars() {
+            if let Some(stripped) = key.strip_prefix(&self.prefix) {
+                if stripped.is_empty() {
+                    continue;
+                }
+
+                let path: Vec<String> = stripped
+                    .split(&self.separator)
+                    .map(|part| part.to_lowercase())
+                    .collect();
+
+                insert_nested(&mut root, &path, parse_scalar(&value));
+            }
+        }
+
+        root
+    }
+}
+
+/// Insert `value` at the nested `path`, creating intermediate objects as needed.
+fn insert_nested(node: &mut Value, path: &[String], value: Value) {
+    if !node.is_object() {
+        *node = Value::Object(serde_json::Map::new());
+    }
+
+    let map = node.as_object_mut().unwrap();
+
+    match path {
+        [] => {}
+        [key] => {
+            map.insert(key.clone(), value);
+        }
+        [key, rest @ ..] => {
+            let child = map
+                .entry(key.clone())
+                .or_insert_with(|| Value::Object(serde_json::Map::new()));
+
+            insert_nested(child, rest, value);
+        }
+    }
+}
+
+/// Parse an environment value as JSON so numbers and booleans survive, falling
+/// back to a plain string when it isn't valid JSON.
+fn parse_scalar(raw: &str) -> Value {
+    serde_json::from_str(raw).unwrap_or_else(|_| Value::String(raw.to_string()))
+}
+
+/// Restrict a freshly-written config file to owner read/write (`0600`).
+#[cfg(unix)]
+fn set_secure_permissions(path: &str) -> Result<(), Error> {
+    use std::os::unix::fs::PermissionsExt;
+
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))
+}
+
+/// Permission hardening is a no-op on non-Unix platforms.
+#[cfg(not(unix))]
+fn set_secure_permissions(_path: &str) -> Result<(), Error> {
+    Ok(())
+}
+
+/// A single migration step applied to the raw config value before it is
+/// deserialized into `T`.
+pub type Migration = Box<dyn Fn(Value) -> Value>;
+
 pub struct ConfigFile<T> {
     pub filename: String,
     pub directories: Vec<String>,
     pub absolute_filepath: Option<String>,
     pub default_config: Option<T>,
     pub create_if_missing: bool,
+    pub format: Option<Format>,
+    pub sources: Vec<Source>,
+    pub merge: bool,
+    pub secure: bool,
+    pub current_version: Option<u32>,
+    pub migrations: Vec<Migration>,
 }
 
-impl<'a, T: Serialize + Deserialize<'a> + Debug + Clone> ConfigFile<T> {
+impl<T: Debug> Debug for ConfigFile<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConfigFile")
+            .field("filename", &self.filename)
+            .field("directories", &self.directories)
+            .field("absolute_filepath", &self.absolute_filepath)
+            .field("default_config", &self.default_config)
+            .field("create_if_missing", &self.create_if_missing)
+            .field("format", &self.format)
+            .field("sources", &self.sources)
+            .field("merge", &self.merge)
+            .field("secure", &self.secure)
+            .field("current_version", &self.current_version)
+            .field("migrations", &format_args!("{} migration(s)", self.migrations.len()))
+            .finish()
+    }
+}
+
+/// Deep-merge `overlay` into `base`: object values recurse key-by-key, while
+/// scalars and arrays replace the existing value outright.
+fn deep_merge(base: &mut Value, overlay: Value) {
+    match (base, overlay) {
+        (Value::Object(base_map), Value::Object(overlay_map)) => {
+            for (key, value) in overlay_map {
+                match base_map.get_mut(&key) {
+                    Some(existing) => deep_merge(existing, value),
+                    None => {
+                        base_map.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
+}
+
+impl<T: Serialize + DeserializeOwned + Debug + Clone> ConfigFile<T> {
     pub fn new(filename: String) -> Self {
         Self {
             filename,
@@ -23,6 +222,12 @@ impl<'a, T: Serialize + Deserialize<'a> + Debug + Clone> ConfigFile<T> {
             absolute_filepath: None,
             default_config: None,
             create_if_missing: false,
+            format: None,
+            sources: vec![],
+            merge: false,
+            secure: false,
+            current_version: None,
+            migrations: vec![],
         }
     }
 
@@ -43,6 +248,186 @@ impl<'a, T: Serialize + Deserialize<'a> + Debug + Clone> ConfigFile<T> {
         self
     }
 
+    /// Restrict any config file figgy creates to owner-only (`0600`) on Unix,
+    /// so files holding tokens or secrets aren't world-readable.
+    pub fn secure(mut self) -> Self {
+        self.secure = true;
+
+        self
+    }
+
+    pub fn format(mut self, format: Format) -> Self {
+        self.format = Some(format);
+
+        self
+    }
+
+    /// Add a single source to the merge pipeline.
+    pub fn source(mut self, source: Source) -> Self {
+        self.sources.push(source);
+
+        self
+    }
+
+    /// Switch `read()` into merge mode, combining every configured source in
+    /// priority order instead of picking a single file.
+    pub fn merge_sources(mut self) -> Self {
+        self.merge = true;
+
+        self
+    }
+
+    /// Set the current schema version that files are migrated up to. Defaults
+    /// to the number of registered migrations when left unset.
+    pub fn version(mut self, version: u32) -> Self {
+        self.current_version = Some(version);
+
+        self
+    }
+
+    /// Register the ordered migration closures used to upgrade older config
+    /// files. Migration `n` upgrades a file from version `n` to `n + 1`.
+    pub fn migrations(mut self, migrations: Vec<Migration>) -> Self {
+        self.migrations = migrations;
+
+        self
+    }
+
+    /// Read a file, running any pending migrations over its raw value and
+    /// rewriting the upgraded file before deserializing into `T`.
+    fn read_with_migrations(&self, file: File, path: Option<String>) -> Result<T, Error> {
+        let mut contents = String::new();
+        let mut file = file;
+        file.read_to_string(&mut contents)?;
+
+        let format = self.resolved_format();
+        let mut value: Value = format.deserialize(&contents)?;
+
+        let file_version = value
+            .get("version")
+            .and_then(|version| version.as_u64())
+            .unwrap_or(0) as u32;
+        let current = self
+            .current_version
+            .unwrap_or(self.migrations.len() as u32);
+
+        if file_version < current {
+            for migration in self
+                .migrations
+                .iter()
+                .take(current as usize)
+                .skip(file_version as usize)
+            {
+                value = migration(value);
+            }
+
+            if let Some(map) = value.as_object_mut() {
+                map.insert("version".to_string(), Value::from(current));
+            }
+
+            if let Some(path) = path {
+                std::fs::write(&path, format.serialize(&value)?)?;
+            }
+        }
+
+        serde_json::from_value(value)
+            .map_err(|error| Error::new(ErrorKind::InvalidData, error))
+    }
+
+    /// Resolve a single source into its parsed value, or `None` when the
+    /// source isn't present (e.g. a missing file or absent default).
+    fn load_source(&self, source: &Source) -> Result<Option<Value>, Error> {
+        match source {
+            Source::Default => match self.default_config {
+                Some(ref config) => {
+                    let value = serde_json::to_value(config)
+                        .map_err(|error| Error::new(ErrorKind::InvalidData, error))?;
+
+                    Ok(Some(value))
+                }
+                None => Ok(None),
+            },
+            Source::File(path) => {
+                if !Path::new(path).exists() {
+                    return Ok(None);
+                }
+
+                let mut contents = String::new();
+                File::open(path)?.read_to_string(&mut contents)?;
+
+                let value = Format::from_filename(path).deserialize(&contents)?;
+
+                Ok(Some(value))
+            }
+            Source::Environment(env) => Ok(Some(env.collect())),
+        }
+    }
+
+    /// Merge every configured source in priority order (earliest lowest) and
+    /// deserialize the combined value into `T`.
+    pub fn read_merged(&self) -> Result<T, Error> {
+        let mut merged = Value::Null;
+
+        for source in &self.sources {
+            if let Some(value) = self.load_source(source)? {
+                deep_merge(&mut merged, value);
+            }
+        }
+
+        serde_json::from_value(merged)
+            .map_err(|error| Error::new(ErrorKind::InvalidData, error))
+    }
+
+    /// The format to use, preferring an explicit override and otherwise
+    /// inferring it from the filename extension.
+    fn resolved_format(&self) -> Format {
+        self.format
+            .unwrap_or_else(|| Format::from_filename(&self.filename))
+    }
+
+    /// The filename stem, used as the application's sub-directory name under
+    /// well-known config locations.
+    fn app_name(&self) -> String {
+        Path::new(&self.filename)
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or(&self.filename)
+            .to_string()
+    }
+
+    /// Search the user's config directory first: `$XDG_CONFIG_HOME/<app>`,
+    /// falling back to `~/.config/<app>` when the variable is unset.
+    pub fn user_config_dir(mut self) -> Self {
+        let base = std::env::var("XDG_CONFIG_HOME")
+            .ok()
+            .filter(|dir| !dir.is_empty())
+            .or_else(|| {
+                std::env::var("HOME")
+                    .ok()
+                    .filter(|home| !home.is_empty())
+                    .map(|home| format!("{}/.config", home))
+            });
+
+        if let Some(base) = base {
+            self.directories
+                .insert(0, format!("{}/{}", base, self.app_name()));
+        }
+
+        self
+    }
+
+    /// Append the system-wide fallbacks (`/etc/<app>` and `/var/<app>`), which
+    /// are searched last.
+    pub fn system_config_dirs(mut self) -> Self {
+        let app = self.app_name();
+
+        for dir in ["/etc", "/var"] {
+            self.directories.push(format!("{}/{}", dir, app));
+        }
+
+        self
+    }
+
     pub fn location(&mut self) -> Result<String, Error> {
         let mut dir: String = "".to_string();
 
@@ -71,11 +456,15 @@ impl<'a, T: Serialize + Deserialize<'a> + Debug + Clone> ConfigFile<T> {
     pub fn get_config_from_default(self, path: Option<String>) -> Result<T, Error> {
         match self.default_config {
             Some(ref config) => {
-                if path.is_some() {
-                    let mut file = File::create(path.unwrap())?;
-                    let config_json = serde_json::to_string_pretty(config)?;
+                if let Some(path) = path {
+                    let mut file = File::create(&path)?;
+                    let serialized = self.resolved_format().serialize(config)?;
 
-                    file.write_all(config_json.as_bytes()).unwrap();
+                    file.write_all(serialized.as_bytes())?;
+
+                    if self.secure {
+                        set_secure_permissions(&path)?;
+                    }
                 }
 
 
@@ -106,23 +495,21 @@ impl<'a, T: Serialize + Deserialize<'a> + Debug + Clone> ConfigFile<T> {
             }
         }
     }
-    pub fn read_file(file: File) -> Result<T, Box<dyn std::error::Error>> {
+
+    pub fn read_file(&self, file: File) -> Result<T, Box<dyn std::error::Error>> {
         let mut contents = String::new();
         let mut file = file;
 
         file.read_to_string(&mut contents)?;
 
-        let boxed_str = contents.into_boxed_str();
-        let static_str = Box::leak(boxed_str);
-
-        let config_data = serde_json::from_str(static_str)?;
+        let config_data = self.resolved_format().deserialize(&contents)?;
 
         Ok(config_data)
     }
-    
+
 
     pub fn get_config_from_file(self, file: File) -> Result<T, Error> {
-        let file_contents = Self::read_file(file);
+        let file_contents = self.read_file(file);
 
         match file_contents {
             Ok(config_data) => Ok(config_data),
@@ -130,21 +517,136 @@ impl<'a, T: Serialize + Deserialize<'a> + Debug + Clone> ConfigFile<T> {
         }
     }
 
+    /// Persist an updated `config` back to disk in the active format.
+    ///
+    /// Parent directories are created as needed and the write is atomic: the
+    /// serialized config is written to a sibling temp file which is then
+    /// renamed over the target, so a crash mid-write can't truncate the
+    /// existing config.
+    pub fn save(&mut self, config: &T) -> Result<(), Error> {
+        let path = match self.location() {
+            Ok(path) => path,
+            Err(_) if !self.directories.is_empty() => {
+                format!("{}/{}", self.directories[0], self.filename)
+            }
+            Err(error) => return Err(error),
+        };
+
+        if let Some(parent) = Path::new(&path).parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let serialized = self.resolved_format().serialize(config)?;
+
+        let temp_path = format!("{}.tmp", path);
+        let mut temp = File::create(&temp_path)?;
+        temp.write_all(serialized.as_bytes())?;
+        temp.sync_all()?;
+
+        std::fs::rename(&temp_path, &path)?;
+
+        if self.secure {
+            set_secure_permissions(&path)?;
+        }
+
+        Ok(())
+    }
+
     pub fn read(mut self) -> Result<T, Error> {
+        if self.merge {
+            return self.read_merged();
+        }
+
         let (filepath, file) = self.get_file();
 
 
         match file {
-            Ok(file_ok) => self.get_config_from_file(file_ok),
+            Ok(file_ok) => {
+                if !self.migrations.is_empty() {
+                    return self.read_with_migrations(file_ok, filepath.ok());
+                }
+
+                self.get_config_from_file(file_ok)
+            }
             Err(_) => self.get_config_from_default(filepath.ok()),
         }
     }
+
+    /// Re-run the read/merge pipeline without consuming the builder, used by
+    /// [`ConfigFile::watch`] to reload on every file change.
+    fn reload(&mut self) -> Result<T, Error> {
+        if self.merge {
+            return self.read_merged();
+        }
+
+        let (filepath, file) = self.get_file();
+
+        match file {
+            Ok(file_ok) => {
+                if !self.migrations.is_empty() {
+                    return self.read_with_migrations(file_ok, filepath.ok());
+                }
+
+                self.read_file(file_ok)
+                    .map_err(|_| Error::new(ErrorKind::NotFound, "File was invalid"))
+            }
+            Err(_) => match self.default_config {
+                Some(ref config) => Ok(config.clone()),
+                None => Err(Error::new(ErrorKind::NotFound, "No default config was provided")),
+            },
+        }
+    }
+
+    /// Read the config once, then watch the resolved path and re-run the full
+    /// read/merge pipeline on every change, invoking `on_change` with the
+    /// freshly parsed `T`.
+    ///
+    /// Rapid successive events are debounced into a single reload, and
+    /// transient deserialization errors (e.g. a half-written file) are ignored
+    /// so the last good config keeps being served. This blocks the calling
+    /// thread for the lifetime of the watch.
+    pub fn watch(mut self, on_change: impl Fn(T)) -> Result<(), Error> {
+        use notify::{RecursiveMode, Watcher};
+        use std::sync::mpsc::channel;
+        use std::time::Duration;
+
+        let path = self.location()?;
+
+        // Serve the initial config immediately.
+        on_change(self.reload()?);
+
+        let (tx, rx) = channel();
+        let mut watcher = notify::recommended_watcher(move |result| {
+            let _ = tx.send(result);
+        })
+        .map_err(std::io::Error::other)?;
+
+        watcher
+            .watch(Path::new(&path), RecursiveMode::NonRecursive)
+            .map_err(std::io::Error::other)?;
+
+        let debounce = Duration::from_millis(200);
+
+        while rx.recv().is_ok() {
+            // Drain any events that arrive within the debounce window so a
+            // burst of writes triggers a single reload.
+            while rx.recv_timeout(debounce).is_ok() {}
+
+            if let Ok(config) = self.reload() {
+                on_change(config);
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::fs::remove_file;
 
+    use serde::Deserialize;
+
     use super::*;
 
     #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -160,7 +662,7 @@ mod tests {
             .read();
 
         assert!(config.is_ok());
-        
+
         let config = config.unwrap();
 
         assert_eq!(config.name, "Daniel");
@@ -178,7 +680,7 @@ mod tests {
             .read();
 
         assert!(config.is_ok());
-        
+
         let config = config.unwrap();
 
         assert_eq!(config.name, "Daniel");
@@ -200,7 +702,7 @@ mod tests {
 
         let created_file = File::open("tests/create_file.json");
 
-        
+
         assert!(created_file.is_ok());
 
         let mut contents = String::new();
@@ -210,4 +712,185 @@ mod tests {
 
         remove_file(Path::new("tests/create_file.json")).unwrap();
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn it_can_read_a_toml_config_file() {
+        let config = ConfigFile::<PersonConfig>::new("person.toml".to_string())
+            .directory("tests".to_string())
+            .read();
+
+        assert!(config.is_ok());
+
+        let config = config.unwrap();
+
+        assert_eq!(config.name, "Daniel");
+        assert_eq!(config.age, 32);
+    }
+
+    #[test]
+    fn it_merges_sources_in_priority_order() {
+        let config = ConfigFile::<PersonConfig>::new("person.json".to_string())
+            .default(PersonConfig {
+                name: "Nobody".to_string(),
+                age: 0,
+            })
+            .source(Source::Default)
+            .source(Source::File("tests/person.json".to_string()))
+            .merge_sources()
+            .read();
+
+        assert!(config.is_ok());
+
+        let config = config.unwrap();
+
+        // The file source wins over the default for every field it provides.
+        assert_eq!(config.name, "Daniel");
+        assert_eq!(config.age, 32);
+    }
+
+    #[test]
+    fn it_migrates_old_config_files_before_deserializing() {
+        std::fs::write(
+            "tests/versioned.json",
+            "{\"name\":\"Old\",\"age\":1,\"version\":0}",
+        )
+        .unwrap();
+
+        let migrations: Vec<Migration> = vec![Box::new(|mut value| {
+            if let Some(map) = value.as_object_mut() {
+                map.insert("name".to_string(), Value::from("Daniel"));
+                map.insert("age".to_string(), Value::from(32));
+            }
+            value
+        })];
+
+        let config = ConfigFile::<PersonConfig>::new("versioned.json".to_string())
+            .directory("tests".to_string())
+            .migrations(migrations)
+            .read()
+            .unwrap();
+
+        assert_eq!(config.name, "Daniel");
+        assert_eq!(config.age, 32);
+
+        // The upgraded file is rewritten with the current version.
+        let mut rewritten = String::new();
+        File::open("tests/versioned.json")
+            .unwrap()
+            .read_to_string(&mut rewritten)
+            .unwrap();
+        assert!(rewritten.contains("\"version\": 1"));
+
+        remove_file(Path::new("tests/versioned.json")).unwrap();
+    }
+
+    #[test]
+    fn it_saves_config_back_to_disk() {
+        let mut store = ConfigFile::<PersonConfig>::new("saved.json".to_string())
+            .directory("tests".to_string())
+            .create_file_if_not_found();
+
+        let updated = PersonConfig {
+            name: "Daniel".to_string(),
+            age: 33,
+        };
+
+        assert!(store.save(&updated).is_ok());
+
+        let reread = ConfigFile::<PersonConfig>::new("saved.json".to_string())
+            .directory("tests".to_string())
+            .read()
+            .unwrap();
+
+        assert_eq!(reread.name, "Daniel");
+        assert_eq!(reread.age, 33);
+
+        remove_file(Path::new("tests/saved.json")).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn it_secures_created_files() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let config = ConfigFile::<PersonConfig>::new("secure_file.json".to_string())
+            .directory("tests".to_string())
+            .create_file_if_not_found()
+            .secure()
+            .default(PersonConfig {
+                name: "Daniel".to_string(),
+                age: 32,
+            })
+            .read();
+
+        assert!(config.is_ok());
+
+        let metadata = std::fs::metadata("tests/secure_file.json").unwrap();
+        assert_eq!(metadata.permissions().mode() & 0o777, 0o600);
+
+        remove_file(Path::new("tests/secure_file.json")).unwrap();
+    }
+
+    #[test]
+    fn it_resolves_well_known_config_directories() {
+        std::env::set_var("XDG_CONFIG_HOME", "/tmp/xdg");
+
+        let config = ConfigFile::<PersonConfig>::new("person.json".to_string())
+            .directory("tests".to_string())
+            .user_config_dir()
+            .system_config_dirs();
+
+        std::env::remove_var("XDG_CONFIG_HOME");
+
+        // User dir first, explicit directories next, system dirs last.
+        assert_eq!(
+            config.directories,
+            vec![
+                "/tmp/xdg/person".to_string(),
+                "tests".to_string(),
+                "/etc/person".to_string(),
+                "/var/person".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_reads_environment_overrides() {
+        std::env::set_var("APP_NAME", "Daniel");
+        std::env::set_var("APP_AGE", "32");
+
+        let config = ConfigFile::<PersonConfig>::new("person.json".to_string())
+            .default(PersonConfig {
+                name: "Nobody".to_string(),
+                age: 0,
+            })
+            .source(Source::Default)
+            .source(Source::Environment(EnvSource::new("APP_".to_string())))
+            .merge_sources()
+            .read();
+
+        std::env::remove_var("APP_NAME");
+        std::env::remove_var("APP_AGE");
+
+        assert!(config.is_ok());
+
+        let config = config.unwrap();
+
+        assert_eq!(config.name, "Daniel");
+        assert_eq!(config.age, 32);
+    }
+
+    #[test]
+    fn it_can_read_a_yaml_config_file() {
+        let config = ConfigFile::<PersonConfig>::new("person.yaml".to_string())
+            .directory("tests".to_string())
+            .read();
+
+        assert!(config.is_ok());
+
+        let config = config.unwrap();
+
+        assert_eq!(config.name, "Daniel");
+        assert_eq!(config.age, 32);
+    }
+}